@@ -0,0 +1,131 @@
+use crate::storage::Candle;
+
+/// Selectable candle timeframe, cycled with a keybinding in the main loop.
+/// Only timeframes the persisted 1h series can actually produce are
+/// offered — `aggregate` can roll candles up into coarser buckets but can't
+/// subdivide them, so nothing below 1h belongs here.
+///
+/// Sub-hour timeframes (1m/5m/15m) are intentionally out of scope: they'd
+/// need tick-level data rolled into buckets as live prices arrive, not just
+/// a coarser read of the 1h series, and nothing in `storage` persists ticks
+/// today. Adding them means a separate tick-aggregation path, not a new
+/// `Resolution` variant over this same `aggregate` function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    OneHour,
+    OneDay,
+}
+
+impl Resolution {
+    pub const ALL: [Resolution; 2] = [Resolution::OneHour, Resolution::OneDay];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Resolution::OneHour => "1h",
+            Resolution::OneDay => "1d",
+        }
+    }
+
+    pub fn next(&self) -> Resolution {
+        let idx = Self::ALL.iter().position(|r| r == self).unwrap();
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    fn bucket_seconds(&self) -> i64 {
+        match self {
+            Resolution::OneHour => 60 * 60,
+            Resolution::OneDay => 24 * 60 * 60,
+        }
+    }
+}
+
+/// Rolls a lower-timeframe candle series into `resolution`-sized candles by
+/// bucketing timestamps and folding open/high/low/close/volume per bucket,
+/// the same way a higher-timeframe worker rolls up from raw ticks.
+pub fn aggregate(candles: &[Candle], resolution: Resolution) -> Vec<Candle> {
+    let bucket_size = resolution.bucket_seconds();
+    if candles.is_empty() {
+        return Vec::new();
+    }
+
+    // Bucket oldest-to-newest so open/close land on the right edge of each bucket.
+    let mut ordered: Vec<&Candle> = candles.iter().collect();
+    ordered.sort_by_key(|c| c.timestamp);
+
+    let mut buckets: Vec<Candle> = Vec::new();
+    let mut current_bucket_ts = i64::MIN;
+
+    for candle in ordered {
+        let bucket_ts = (candle.timestamp / bucket_size) * bucket_size;
+        if bucket_ts != current_bucket_ts {
+            current_bucket_ts = bucket_ts;
+            buckets.push(Candle {
+                timestamp: bucket_ts,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+            });
+        } else {
+            let bucket = buckets.last_mut().unwrap();
+            bucket.high = bucket.high.max(candle.high);
+            bucket.low = bucket.low.min(candle.low);
+            bucket.close = candle.close;
+            bucket.volume += candle.volume;
+        }
+    }
+
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(timestamp: i64, open: f64, high: f64, low: f64, close: f64, volume: f64) -> Candle {
+        Candle { timestamp, open, high, low, close, volume }
+    }
+
+    #[test]
+    fn aggregate_on_empty_input_is_empty() {
+        assert!(aggregate(&[], Resolution::OneDay).is_empty());
+    }
+
+    #[test]
+    fn aggregate_folds_same_bucket_candles_and_sorts_out_of_order_input() {
+        let candles = vec![
+            // Deliberately out of timestamp order.
+            candle(7200, 3.0, 4.0, 2.0, 2.5, 5.0),
+            candle(0, 1.0, 5.0, 0.5, 2.0, 10.0),
+            candle(3600, 2.0, 6.0, 1.0, 3.0, 20.0),
+            candle(86400, 10.0, 12.0, 9.0, 11.0, 100.0),
+        ];
+
+        let daily = aggregate(&candles, Resolution::OneDay);
+
+        assert_eq!(daily.len(), 2);
+
+        let day0 = &daily[0];
+        assert_eq!(day0.timestamp, 0);
+        assert_eq!(day0.open, 1.0); // open of the earliest candle in the bucket
+        assert_eq!(day0.high, 6.0); // max high across the bucket
+        assert_eq!(day0.low, 0.5); // min low across the bucket
+        assert_eq!(day0.close, 2.5); // close of the latest candle in the bucket
+        assert_eq!(day0.volume, 35.0); // summed volume
+
+        let day1 = &daily[1];
+        assert_eq!(day1.timestamp, 86400);
+        assert_eq!(day1.close, 11.0);
+        assert_eq!(day1.volume, 100.0);
+    }
+
+    #[test]
+    fn aggregate_to_one_hour_is_a_passthrough_for_already_hourly_candles() {
+        let candles = vec![candle(0, 1.0, 2.0, 0.5, 1.5, 10.0), candle(3600, 1.5, 2.5, 1.0, 2.0, 20.0)];
+        let hourly = aggregate(&candles, Resolution::OneHour);
+        assert_eq!(hourly.len(), 2);
+        assert_eq!(hourly[0].timestamp, 0);
+        assert_eq!(hourly[1].timestamp, 3600);
+    }
+}
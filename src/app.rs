@@ -0,0 +1,84 @@
+use crate::currency::Currency;
+
+/// Which panel arrow keys / PageUp / PageDown currently act on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    Stocks,
+    Indicators,
+    News,
+}
+
+impl Focus {
+    pub fn next(&self) -> Focus {
+        match self {
+            Focus::Stocks => Focus::Indicators,
+            Focus::Indicators => Focus::News,
+            Focus::News => Focus::Stocks,
+        }
+    }
+}
+
+/// Interactive UI state: the live watchlist, the selection cursor into it,
+/// which panel is focused, the news scroll offset, and an in-progress
+/// symbol entry. Replaces the locals `main` used to hold directly.
+pub struct App {
+    pub symbols: Vec<String>,
+    pub selected: usize,
+    pub focus: Focus,
+    pub news_scroll: u16,
+    pub input_buffer: Option<String>,
+    pub currency: Currency,
+}
+
+impl App {
+    pub fn new(symbols: Vec<String>) -> Self {
+        Self {
+            symbols,
+            selected: 0,
+            focus: Focus::Stocks,
+            news_scroll: 0,
+            input_buffer: None,
+            currency: Currency::Usd,
+        }
+    }
+
+    pub fn move_selection(&mut self, delta: isize) {
+        if self.symbols.is_empty() {
+            return;
+        }
+        let len = self.symbols.len() as isize;
+        self.selected = (self.selected as isize + delta).rem_euclid(len) as usize;
+    }
+
+    pub fn selected_symbol(&self) -> Option<&str> {
+        self.symbols.get(self.selected).map(String::as_str)
+    }
+
+    /// Removes the selected symbol, pulling the cursor back if it now
+    /// points past the end of the (shorter) list.
+    pub fn remove_selected(&mut self) -> Option<String> {
+        if self.symbols.is_empty() {
+            return None;
+        }
+        let removed = self.symbols.remove(self.selected);
+        if self.selected >= self.symbols.len() && self.selected > 0 {
+            self.selected -= 1;
+        }
+        Some(removed)
+    }
+
+    /// Adds a symbol to the watchlist unless it's already tracked. Returns
+    /// whether it was actually inserted, so the caller knows whether to
+    /// fetch fresh data for it.
+    pub fn add_symbol(&mut self, symbol: String) -> bool {
+        if symbol.is_empty() || self.symbols.contains(&symbol) {
+            return false;
+        }
+        self.symbols.push(symbol);
+        true
+    }
+
+    pub fn scroll_news(&mut self, delta: i32) {
+        self.news_scroll = (self.news_scroll as i32 + delta).max(0) as u16;
+    }
+}
@@ -0,0 +1,21 @@
+use std::fs;
+
+const WATCHLIST_PATH: &str = "watchlist.json";
+
+/// Loads the persisted watchlist, falling back to `default` when the file
+/// is missing or unreadable (e.g. first-ever launch).
+pub fn load_watchlist(default: &[&str]) -> Vec<String> {
+    fs::read_to_string(WATCHLIST_PATH)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_else(|| default.iter().map(|s| s.to_string()).collect())
+}
+
+/// Persists the watchlist so it reloads on the next launch.
+pub fn save_watchlist(symbols: &[String]) {
+    if let Ok(json) = serde_json::to_string_pretty(symbols) {
+        if let Err(e) = fs::write(WATCHLIST_PATH, json) {
+            eprintln!("Error saving watchlist: {}", e);
+        }
+    }
+}
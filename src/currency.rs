@@ -0,0 +1,52 @@
+use serde_json::Value;
+
+/// Display currency the HUD prices are shown in, flipped with a keybinding
+/// without touching the underlying (always-USD) watchlist data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Currency {
+    Usd,
+    Eur,
+    Btc,
+}
+
+impl Currency {
+    pub const ALL: [Currency; 3] = [Currency::Usd, Currency::Eur, Currency::Btc];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Currency::Usd => "USD",
+            Currency::Eur => "EUR",
+            Currency::Btc => "BTC",
+        }
+    }
+
+    pub fn next(&self) -> Currency {
+        let idx = Self::ALL.iter().position(|c| c == self).unwrap();
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+}
+
+fn latest_close(stock_data: &[(String, Value)], symbol: &str) -> Option<f64> {
+    stock_data
+        .iter()
+        .find(|(s, _)| s == symbol)
+        .and_then(|(_, data)| data["values"].as_array())
+        .and_then(|values| values.first())
+        .and_then(|candle| candle["close"].as_str())
+        .and_then(|s| s.parse().ok())
+}
+
+/// Multiplier that converts a USD-denominated price into `currency`, read
+/// off the EUR/USD and BTC/USD pairs already in the watchlist. Both quotes
+/// are USD-per-unit-of-currency, so both branches invert the same way.
+pub fn conversion_rate(currency: Currency, stock_data: &[(String, Value)]) -> f64 {
+    match currency {
+        Currency::Usd => 1.0,
+        Currency::Eur => latest_close(stock_data, "EUR/USD")
+            .filter(|rate| *rate > 0.0)
+            .map_or(1.0, |rate| 1.0 / rate),
+        Currency::Btc => latest_close(stock_data, "BTC/USD")
+            .filter(|rate| *rate > 0.0)
+            .map_or(1.0, |rate| 1.0 / rate),
+    }
+}
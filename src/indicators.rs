@@ -0,0 +1,197 @@
+use serde_json::Value;
+
+use crate::TechnicalIndicators;
+
+/// Parses the `close` field of a Twelve Data `values` entry, matching the
+/// lenient `unwrap_or` parsing used elsewhere when reading this payload.
+fn close(candle: &Value) -> f64 {
+    candle["close"].as_str().unwrap_or("0").parse().unwrap_or(0.0)
+}
+
+/// Derives `TechnicalIndicators` from the candle array Twelve Data already
+/// returns for the price series (newest first), instead of issuing a
+/// separate `technical_indicator` request per indicator.
+pub fn compute_technical_indicators(values: &[Value]) -> TechnicalIndicators {
+    // The indicator math below reads most naturally oldest-to-newest.
+    let closes: Vec<f64> = values.iter().rev().map(close).collect();
+
+    let (macd, macd_signal) = macd(&closes);
+
+    TechnicalIndicators {
+        sma50: sma(&closes, 50),
+        sma200: sma(&closes, 200),
+        rsi: rsi(&closes, 14),
+        macd,
+        macd_signal,
+        bb_upper: bbands(&closes, 20).map(|(upper, _, _)| upper),
+        bb_middle: bbands(&closes, 20).map(|(_, middle, _)| middle),
+        bb_lower: bbands(&closes, 20).map(|(_, _, lower)| lower),
+    }
+}
+
+/// Simple moving average of the last `period` closes.
+fn sma(closes: &[f64], period: usize) -> Option<f64> {
+    if closes.len() < period {
+        return None;
+    }
+    let window = &closes[closes.len() - period..];
+    Some(window.iter().sum::<f64>() / period as f64)
+}
+
+/// Exponential moving average, seeded from the SMA of the first `period`
+/// closes, as in the usual EMA_t = (close_t − EMA_{t-1})·k + EMA_{t-1}
+/// recurrence with k = 2/(period+1).
+fn ema_series(closes: &[f64], period: usize) -> Option<Vec<f64>> {
+    if closes.len() < period {
+        return None;
+    }
+    let k = 2.0 / (period as f64 + 1.0);
+    let seed = closes[..period].iter().sum::<f64>() / period as f64;
+    let mut series = Vec::with_capacity(closes.len() - period + 1);
+    series.push(seed);
+    for &close in &closes[period..] {
+        let prev = *series.last().unwrap();
+        series.push((close - prev) * k + prev);
+    }
+    Some(series)
+}
+
+fn ema(closes: &[f64], period: usize) -> Option<f64> {
+    ema_series(closes, period).map(|series| *series.last().unwrap())
+}
+
+/// MACD = EMA(12) − EMA(26), plus its signal line (EMA(9) of the MACD
+/// series) used by the crossover rule in the signal evaluator.
+fn macd(closes: &[f64]) -> (Option<f64>, Option<f64>) {
+    let (Some(ema12), Some(ema26)) = (ema_series(closes, 12), ema_series(closes, 26)) else {
+        return (None, None);
+    };
+    // ema_series(12) is longer than ema_series(26); align both to the tail
+    // so each entry is the MACD at the same point in time.
+    let offset = ema12.len() - ema26.len();
+    let macd_series: Vec<f64> = ema12[offset..].iter().zip(ema26.iter()).map(|(a, b)| a - b).collect();
+
+    let macd_latest = macd_series.last().copied();
+    let signal_latest = ema_series(&macd_series, 9).map(|series| *series.last().unwrap());
+    (macd_latest, signal_latest)
+}
+
+/// RSI(14) via Wilder's smoothing: seed avgGain/avgLoss as the mean
+/// gain/loss over the first 14 deltas, then smooth with a 1/14 weight.
+fn rsi(closes: &[f64], period: usize) -> Option<f64> {
+    if closes.len() < period + 1 {
+        return None;
+    }
+    let deltas: Vec<f64> = closes.windows(2).map(|w| w[1] - w[0]).collect();
+
+    let seed_gains: f64 = deltas[..period].iter().map(|d| d.max(0.0)).sum();
+    let seed_losses: f64 = deltas[..period].iter().map(|d| (-d).max(0.0)).sum();
+    let mut avg_gain = seed_gains / period as f64;
+    let mut avg_loss = seed_losses / period as f64;
+
+    for &delta in &deltas[period..] {
+        let gain = delta.max(0.0);
+        let loss = (-delta).max(0.0);
+        avg_gain = (avg_gain * (period as f64 - 1.0) + gain) / period as f64;
+        avg_loss = (avg_loss * (period as f64 - 1.0) + loss) / period as f64;
+    }
+
+    if avg_loss == 0.0 {
+        return Some(100.0);
+    }
+    let rs = avg_gain / avg_loss;
+    Some(100.0 - 100.0 / (1.0 + rs))
+}
+
+/// Bollinger Bands: SMA(20) middle band, upper/lower = middle ± 2·population
+/// standard deviation of the last 20 closes.
+fn bbands(closes: &[f64], period: usize) -> Option<(f64, f64, f64)> {
+    if closes.len() < period {
+        return None;
+    }
+    let window = &closes[closes.len() - period..];
+    let middle = window.iter().sum::<f64>() / period as f64;
+    let variance = window.iter().map(|c| (c - middle).powi(2)).sum::<f64>() / period as f64;
+    let stddev = variance.sqrt();
+    Some((middle + 2.0 * stddev, middle, middle - 2.0 * stddev))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn close_value(close: f64) -> Value {
+        serde_json::json!({ "close": close.to_string() })
+    }
+
+    #[test]
+    fn sma_averages_the_trailing_window() {
+        let closes = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(sma(&closes, 3), Some(4.0)); // (3+4+5)/3
+        assert_eq!(sma(&closes, 6), None); // not enough data yet
+    }
+
+    #[test]
+    fn ema_seeds_from_the_sma_then_smooths() {
+        let closes = [1.0, 2.0, 3.0];
+        // seed = (1+2)/2 = 1.5, k = 2/3, ema = (3 - 1.5) * 2/3 + 1.5 = 2.5
+        assert_eq!(ema(&closes, 2), Some(2.5));
+    }
+
+    #[test]
+    fn rsi_matches_wilders_formula_on_a_short_series() {
+        // deltas = [+2, -1] -> avg_gain = 1.0, avg_loss = 0.5, RS = 2.0
+        let closes = [10.0, 12.0, 11.0];
+        let rsi_value = rsi(&closes, 2).unwrap();
+        assert!((rsi_value - 100.0 / 1.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn rsi_is_100_when_there_are_no_losses() {
+        let closes = [1.0, 2.0, 3.0];
+        assert_eq!(rsi(&closes, 2), Some(100.0));
+    }
+
+    #[test]
+    fn macd_is_zero_for_a_flat_series() {
+        let closes = vec![10.0; 40];
+        let (macd_value, signal_value) = macd(&closes);
+        assert!((macd_value.unwrap()).abs() < EPSILON);
+        assert!((signal_value.unwrap()).abs() < EPSILON);
+    }
+
+    #[test]
+    fn bbands_matches_hand_computed_population_stddev() {
+        // mean = 2.5, population variance = 1.25, stddev = sqrt(1.25)
+        let closes = [1.0, 2.0, 3.0, 4.0];
+        let (upper, middle, lower) = bbands(&closes, 4).unwrap();
+        let stddev = 1.25_f64.sqrt();
+        assert!((middle - 2.5).abs() < EPSILON);
+        assert!((upper - (2.5 + 2.0 * stddev)).abs() < EPSILON);
+        assert!((lower - (2.5 - 2.0 * stddev)).abs() < EPSILON);
+    }
+
+    #[test]
+    fn compute_technical_indicators_is_none_when_history_is_too_short() {
+        let values: Vec<Value> = (0..5).map(|i| close_value(i as f64)).collect();
+        let indicators = compute_technical_indicators(&values);
+        assert_eq!(indicators.sma50, None);
+        assert_eq!(indicators.rsi, None);
+        assert_eq!(indicators.macd, None);
+        assert_eq!(indicators.bb_upper, None);
+    }
+
+    #[test]
+    fn compute_technical_indicators_on_a_flat_series() {
+        let values: Vec<Value> = std::iter::repeat(close_value(10.0)).take(210).collect();
+        let indicators = compute_technical_indicators(&values);
+        assert_eq!(indicators.sma50, Some(10.0));
+        assert_eq!(indicators.sma200, Some(10.0));
+        assert_eq!(indicators.rsi, Some(100.0));
+        assert!((indicators.macd.unwrap()).abs() < EPSILON);
+        assert!((indicators.bb_upper.unwrap() - 10.0).abs() < EPSILON);
+        assert!((indicators.bb_lower.unwrap() - 10.0).abs() < EPSILON);
+    }
+}
@@ -0,0 +1,14 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+
+const LOG_PATH: &str = "trading_display.log";
+
+/// Appends a timestamped error line to `LOG_PATH`. The app runs the whole
+/// time in an alternate-screen raw-mode TUI, so writing to stderr would
+/// land in the middle of the rendered frame instead of anywhere visible.
+pub fn log_error(message: &str) {
+    let Ok(mut file) = OpenOptions::new().create(true).append(true).open(LOG_PATH) else {
+        return;
+    };
+    let _ = writeln!(file, "[{}] {}", chrono::Utc::now().to_rfc3339(), message);
+}
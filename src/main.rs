@@ -1,13 +1,24 @@
+mod aggregate;
+mod app;
+mod config;
+mod currency;
+mod indicators;
+mod logging;
+mod signals;
+mod storage;
+mod streaming;
+
 use anyhow::Result;
 use reqwest::Client;
 use serde_json::Value;
-use std::{io, time::Duration};
+use std::{collections::HashMap, io, time::Duration};
 use tui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
+    symbols,
     text::{Span, Spans},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph},
     Terminal,
 };
 use crossterm::{
@@ -16,14 +27,32 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use futures::future::join_all;
-use tokio::time;
+use tokio::{sync::mpsc, time};
+
+use aggregate::{aggregate, Resolution};
+use app::{App, Focus};
+use signals::Signal;
+use storage::{Candle, SqliteStorage, Storage};
+use streaming::{spawn_price_feed, PriceMap, PriceUpdate};
+
+const REST_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+const DB_PATH: &str = "trading_display.sqlite";
+const HOURLY_RESOLUTION: &str = "1h";
+/// Candles to request per REST refresh — deep enough that SMA200 and the
+/// MACD signal line (34+ closes) still have a full lookback even if the
+/// persisted-history read (see `load_indicator_history`) is unavailable.
+const REST_FETCH_OUTPUT_SIZE: u32 = 250;
+/// How many persisted 1h candles to load per symbol for indicator math —
+/// deep enough to seed SMA200 the moment the app starts, and kept that way
+/// across the app's life instead of riding on the REST snapshot's depth.
+const INDICATOR_LOOKBACK: usize = 250;
 
-const STOCK_API_URL: &str = "https://api.twelvedata.com/time_series";
+pub(crate) const STOCK_API_URL: &str = "https://api.twelvedata.com/time_series";
 const STOCK_API_KEY: &str = "ab9e27fedd3d4c4bb83c314a03ce4cd1";
 const STOCK_SYMBOLS: &[&str] = &[
     "AAPL",
     "EUR/USD",
-    "ETH/BTC:Huobi",
+    "BTC/USD",
     "TRP:TSX",
     "RHM.DE",
     "GOOG",
@@ -34,17 +63,17 @@ const STOCK_SYMBOLS: &[&str] = &[
 ];
 const NEWS_API_URL: &str = "https://api.marketaux.com/v1/news/all";
 const NEWS_API_KEY: &str = "UIg3lYafKnwqxNHmYPc2h282hN9zmhdLrmkz7PJK";
-const TECH_UPDATE_INTERVAL: usize = 10;
-
-#[derive(Debug)]
-struct TechnicalIndicators {
-    sma50: Option<f64>,
-    sma200: Option<f64>,
-    rsi: Option<f64>,
-    macd: Option<f64>,
-    bb_upper: Option<f64>,
-    bb_middle: Option<f64>,
-    bb_lower: Option<f64>,
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TechnicalIndicators {
+    pub(crate) sma50: Option<f64>,
+    pub(crate) sma200: Option<f64>,
+    pub(crate) rsi: Option<f64>,
+    pub(crate) macd: Option<f64>,
+    pub(crate) macd_signal: Option<f64>,
+    pub(crate) bb_upper: Option<f64>,
+    pub(crate) bb_middle: Option<f64>,
+    pub(crate) bb_lower: Option<f64>,
 }
 
 #[tokio::main]
@@ -56,21 +85,147 @@ async fn main() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     let client = Client::new();
-    let symbols: Vec<String> = STOCK_SYMBOLS.iter().map(|&s| s.to_string()).collect();
-    let mut interval = time::interval(Duration::from_secs(30));
-    let mut cycle_count = 0;
-    let mut technical_data: Vec<(String, TechnicalIndicators)> = Vec::new();
+    let mut app = App::new(config::load_watchlist(STOCK_SYMBOLS));
+    let mut rest_interval = time::interval(REST_REFRESH_INTERVAL);
+
+    let storage = SqliteStorage::connect(DB_PATH).await?;
+    for symbol in &app.symbols {
+        if let Err(e) = storage.backfill(&client, symbol, HOURLY_RESOLUTION, STOCK_API_KEY).await {
+            logging::log_error(&format!("Error backfilling {}: {}", symbol, e));
+        }
+    }
+    let mut stock_data = hydrate_stock_data(&storage, &app.symbols).await;
+    if stock_data.iter().all(|(_, data)| data["values"].as_array().map_or(true, |v| v.is_empty())) {
+        // Nothing on disk yet (first-ever launch) — fall back to a live fetch.
+        stock_data = fetch_all_stock_data(&client, &app.symbols).await;
+    }
+    let mut indicator_history = load_indicator_history(&storage, &app.symbols).await;
+    let mut technical_data = compute_all_technical_data(&stock_data, &indicator_history);
+    let mut news_data: Vec<(String, Value)> = Vec::new();
+
+    let (mut prices, mut price_rx, mut price_feed_handle) =
+        spawn_price_feed(STOCK_API_KEY.to_string(), app.symbols.clone());
+    let mut key_rx = spawn_key_events();
+
+    let mut resolution = Resolution::OneHour;
+    let mut chart_candles = match app.selected_symbol() {
+        Some(symbol) => load_chart_candles(&storage, symbol, resolution).await,
+        None => Vec::new(),
+    };
+
+    let mut prev_technical_data: HashMap<String, TechnicalIndicators> = HashMap::new();
+    let mut prev_close_data: HashMap<String, f64> = HashMap::new();
+    let mut signal_history: Vec<Signal> = Vec::new();
 
     loop {
-        interval.tick().await;
-        cycle_count += 1;
+        let mut resolution_changed = false;
+        let mut watchlist_changed = false;
+        let mut candles_refreshed = false;
 
-        let stock_data = fetch_all_stock_data(&client, &symbols).await;
-        let news_data = fetch_relevant_news(&client, &stock_data).await;
+        tokio::select! {
+            Some(_) = price_rx.recv() => {
+                // The tick itself only wakes the loop; sync_live_prices below
+                // reads the shared map, which the feed task already updated.
+            }
+            _ = rest_interval.tick() => {
+                stock_data = fetch_all_stock_data(&client, &app.symbols).await;
+                news_data = fetch_relevant_news(&client, &stock_data).await;
+                persist_stock_data(&storage, &stock_data, HOURLY_RESOLUTION).await;
+                indicator_history = load_indicator_history(&storage, &app.symbols).await;
+                resolution_changed = true;
+                candles_refreshed = true;
+            }
+            Some(event) = key_rx.recv() => {
+                if let Event::Key(key) = event {
+                    if let Some(buffer) = app.input_buffer.as_mut() {
+                        match key.code {
+                            KeyCode::Enter => {
+                                let symbol = buffer.trim().to_uppercase();
+                                app.input_buffer = None;
+                                if app.add_symbol(symbol.clone()) {
+                                    config::save_watchlist(&app.symbols);
+                                    if let Err(e) = storage.backfill(&client, &symbol, HOURLY_RESOLUTION, STOCK_API_KEY).await {
+                                        logging::log_error(&format!("Error backfilling {}: {}", symbol, e));
+                                    }
+                                    stock_data = fetch_all_stock_data(&client, &app.symbols).await;
+                                    persist_stock_data(&storage, &stock_data, HOURLY_RESOLUTION).await;
+                                    indicator_history = load_indicator_history(&storage, &app.symbols).await;
+                                    respawn_price_feed(&mut prices, &mut price_rx, &mut price_feed_handle, app.symbols.clone());
+                                    watchlist_changed = true;
+                                    candles_refreshed = true;
+                                }
+                            }
+                            KeyCode::Esc => app.input_buffer = None,
+                            KeyCode::Backspace => { buffer.pop(); }
+                            KeyCode::Char(c) => buffer.push(c),
+                            _ => {}
+                        }
+                    } else {
+                        match key.code {
+                            KeyCode::Char('q') => break,
+                            KeyCode::Char('r') => {
+                                resolution = resolution.next();
+                                resolution_changed = true;
+                            }
+                            KeyCode::Tab => app.focus = app.focus.next(),
+                            KeyCode::Char('c') => app.currency = app.currency.next(),
+                            KeyCode::Up if app.focus == Focus::Stocks => {
+                                app.move_selection(-1);
+                                resolution_changed = true;
+                            }
+                            KeyCode::Down if app.focus == Focus::Stocks => {
+                                app.move_selection(1);
+                                resolution_changed = true;
+                            }
+                            KeyCode::PageUp if app.focus == Focus::News => app.scroll_news(-5),
+                            KeyCode::PageDown if app.focus == Focus::News => app.scroll_news(5),
+                            KeyCode::Char('+') => app.input_buffer = Some(String::new()),
+                            KeyCode::Char('-') => {
+                                if let Some(removed) = app.remove_selected() {
+                                    config::save_watchlist(&app.symbols);
+                                    stock_data.retain(|(symbol, _)| symbol != &removed);
+                                    prev_technical_data.remove(&removed);
+                                    prev_close_data.remove(&removed);
+                                    indicator_history.remove(&removed);
+                                    respawn_price_feed(&mut prices, &mut price_rx, &mut price_feed_handle, app.symbols.clone());
+                                    watchlist_changed = true;
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
 
-        if cycle_count % TECH_UPDATE_INTERVAL == 0 {
-            technical_data = fetch_all_technical_data(&client, &symbols).await;
+        sync_live_prices(&mut stock_data, &prices).await;
+        technical_data = compute_all_technical_data(&stock_data, &indicator_history);
+        if candles_refreshed {
+            evaluate_signals(
+                &stock_data,
+                &technical_data,
+                &mut prev_technical_data,
+                &mut prev_close_data,
+                &mut signal_history,
+            );
+        }
+        if watchlist_changed {
+            resolution_changed = true;
         }
+        if resolution_changed {
+            chart_candles = match app.selected_symbol() {
+                Some(symbol) => load_chart_candles(&storage, symbol, resolution).await,
+                None => Vec::new(),
+            };
+        }
+
+        let selected_symbol = app.selected_symbol().unwrap_or("").to_string();
+        let focused_indicators = technical_data
+            .iter()
+            .find(|(symbol, _)| symbol == &selected_symbol)
+            .map(|(_, indicators)| indicators);
+        let fx_rate = currency::conversion_rate(app.currency, &stock_data);
+        let chart_series = compute_chart_series(&chart_candles, focused_indicators, fx_rate);
 
         terminal.draw(|f| {
             let chunks = Layout::default()
@@ -78,41 +233,52 @@ async fn main() -> Result<()> {
                 .margin(1)
                 .constraints(
                     [
-                        Constraint::Percentage(10),
-                        Constraint::Percentage(40),
-                        Constraint::Percentage(30),
+                        Constraint::Percentage(7),
                         Constraint::Percentage(20),
+                        Constraint::Percentage(15),
+                        Constraint::Percentage(25),
+                        Constraint::Percentage(18),
+                        Constraint::Percentage(15),
                     ]
                     .as_ref(),
                 )
                 .split(f.size());
 
-            let header = Paragraph::new("Trading Data HUD")
+            let header_text = match &app.input_buffer {
+                Some(buffer) => format!(
+                    "Trading Data HUD [{} | {}] — add symbol: {}_",
+                    resolution.label(),
+                    app.currency.label(),
+                    buffer
+                ),
+                None => format!("Trading Data HUD [{} | {}]", resolution.label(), app.currency.label()),
+            };
+            let header = Paragraph::new(header_text)
                 .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
                 .block(Block::default().borders(Borders::ALL).title("Header"));
 
-            let stock_paragraph = Paragraph::new(format_stock_data(&stock_data))
-                .block(Block::default().borders(Borders::ALL).title("Stocks"));
+            let stock_paragraph = Paragraph::new(format_stock_data(&stock_data, &app, fx_rate))
+                .block(panel_block("Stocks", app.focus == Focus::Stocks));
 
-            let indicator_paragraph = Paragraph::new(format_indicator_data(&technical_data))
-                .block(Block::default().borders(Borders::ALL).title("Technical Indicators"));
+            let indicator_paragraph = Paragraph::new(format_indicator_data(&technical_data, fx_rate))
+                .block(panel_block("Technical Indicators", app.focus == Focus::Indicators));
+
+            let chart = build_price_chart(&selected_symbol, &chart_series);
 
             let news_paragraph = Paragraph::new(format_news_data(&news_data))
-                .block(Block::default().borders(Borders::ALL).title("News"));
+                .block(panel_block("News", app.focus == Focus::News))
+                .scroll((app.news_scroll, 0));
+
+            let signals_paragraph = Paragraph::new(format_signal_data(&signal_history))
+                .block(Block::default().borders(Borders::ALL).title("Signals"));
 
             f.render_widget(header, chunks[0]);
             f.render_widget(stock_paragraph, chunks[1]);
             f.render_widget(indicator_paragraph, chunks[2]);
-            f.render_widget(news_paragraph, chunks[3]);
+            f.render_widget(chart, chunks[3]);
+            f.render_widget(news_paragraph, chunks[4]);
+            f.render_widget(signals_paragraph, chunks[5]);
         })?;
-
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                if key.code == KeyCode::Char('q') {
-                    break;
-                }
-            }
-        }
     }
 
     disable_raw_mode()?;
@@ -125,6 +291,303 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Pumps crossterm key events onto a channel so the main loop can `select!`
+/// on them alongside the price feed and the REST refresh timer instead of
+/// blocking on `event::read`.
+fn spawn_key_events() -> mpsc::Receiver<Event> {
+    let (tx, rx) = mpsc::channel(32);
+    tokio::task::spawn_blocking(move || loop {
+        match event::poll(Duration::from_millis(100)) {
+            Ok(true) => match event::read() {
+                Ok(ev) => {
+                    if tx.blocking_send(ev).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => break,
+            },
+            Ok(false) => continue,
+            Err(_) => break,
+        }
+    });
+    rx
+}
+
+/// Tears down the running price-feed task and starts a fresh one
+/// subscribed to `symbols` — the feed only sends its subscription once at
+/// connect time, so a symbol added or removed after that needs a whole new
+/// connection to actually pick up the change.
+fn respawn_price_feed(
+    prices: &mut PriceMap,
+    price_rx: &mut mpsc::Receiver<PriceUpdate>,
+    price_feed_handle: &mut tokio::task::JoinHandle<()>,
+    symbols: Vec<String>,
+) {
+    price_feed_handle.abort();
+    let (new_prices, new_rx, new_handle) = spawn_price_feed(STOCK_API_KEY.to_string(), symbols);
+    *prices = new_prices;
+    *price_rx = new_rx;
+    *price_feed_handle = new_handle;
+}
+
+/// Reconciles the whole stock snapshot against the live price map on every
+/// render tick, covering symbols the feed has updated since the last
+/// `PriceUpdate` was drained (e.g. several ticks coalesced into one).
+/// Only the in-progress bar (`values[0]`) is touched — every older entry is
+/// a closed candle from persisted history and must stay exactly as fetched,
+/// or the indicator lookback ends up computed off a corrupted bar.
+async fn sync_live_prices(stock_data: &mut [(String, Value)], prices: &PriceMap) {
+    let map = prices.lock().await;
+    for (symbol, data) in stock_data.iter_mut() {
+        if let Some(snapshot) = map.get(symbol) {
+            if let Some(values) = data["values"].as_array_mut() {
+                if values.is_empty() {
+                    values.push(serde_json::json!({ "close": snapshot.latest.to_string() }));
+                } else {
+                    values[0]["close"] = Value::String(snapshot.latest.to_string());
+                }
+            }
+        }
+    }
+}
+
+/// Loads whatever history each symbol has on disk and shapes it like a
+/// Twelve Data `time_series` response (newest candle first) so the
+/// existing formatting and indicator code can read it unchanged.
+async fn hydrate_stock_data(storage: &SqliteStorage, symbols: &[String]) -> Vec<(String, Value)> {
+    let mut out = Vec::with_capacity(symbols.len());
+    for symbol in symbols {
+        let candles = storage
+            .load_recent(symbol, HOURLY_RESOLUTION, 200)
+            .await
+            .unwrap_or_default();
+        let values: Vec<Value> = candles.iter().map(candle_to_value).collect();
+        out.push((symbol.clone(), serde_json::json!({ "values": values })));
+    }
+    out
+}
+
+/// Loads each symbol's deep persisted history for `compute_all_technical_data`,
+/// so indicator math draws from the storage backfill/persist layer instead
+/// of whatever depth the live REST snapshot happens to carry.
+async fn load_indicator_history(storage: &SqliteStorage, symbols: &[String]) -> HashMap<String, Vec<Candle>> {
+    let mut history = HashMap::with_capacity(symbols.len());
+    for symbol in symbols {
+        let candles = storage
+            .load_recent(symbol, HOURLY_RESOLUTION, INDICATOR_LOOKBACK)
+            .await
+            .unwrap_or_default();
+        history.insert(symbol.clone(), candles);
+    }
+    history
+}
+
+fn candle_to_value(candle: &Candle) -> Value {
+    serde_json::json!({
+        "datetime": candle.timestamp,
+        "open": candle.open.to_string(),
+        "high": candle.high.to_string(),
+        "low": candle.low.to_string(),
+        "close": candle.close.to_string(),
+        "volume": candle.volume.to_string(),
+    })
+}
+
+/// Persists every freshly-fetched candle so the next launch can hydrate
+/// from disk instead of starting cold.
+async fn persist_stock_data(storage: &SqliteStorage, stock_data: &[(String, Value)], resolution: &str) {
+    for (symbol, data) in stock_data {
+        let Some(values) = data["values"].as_array() else {
+            continue;
+        };
+        let candles: Vec<Candle> = values.iter().filter_map(storage::parse_candle).collect();
+        if !candles.is_empty() {
+            if let Err(e) = storage.persist_candles(symbol, resolution, &candles).await {
+                logging::log_error(&format!("Error persisting candles for {}: {}", symbol, e));
+            }
+        }
+    }
+}
+
+/// Loads stored 1h candles for `symbol` and rolls them up to `resolution`
+/// for the chart panel, re-aggregating from disk rather than keeping a
+/// separate series per timeframe in memory.
+async fn load_chart_candles(storage: &SqliteStorage, symbol: &str, resolution: Resolution) -> Vec<Candle> {
+    let hourly = storage
+        .load_recent(symbol, HOURLY_RESOLUTION, 2000)
+        .await
+        .unwrap_or_default();
+    aggregate(&hourly, resolution)
+}
+
+/// Owned point series for the chart panel, computed once per render so the
+/// `Chart`/`Dataset` borrows built inside `terminal.draw` have somewhere
+/// live to point at.
+struct ChartSeries {
+    close: Vec<(f64, f64)>,
+    sma50: Vec<(f64, f64)>,
+    sma200: Vec<(f64, f64)>,
+    bb_upper: Vec<(f64, f64)>,
+    bb_lower: Vec<(f64, f64)>,
+}
+
+fn compute_chart_series(candles: &[Candle], indicators: Option<&TechnicalIndicators>, fx_rate: f64) -> ChartSeries {
+    let close: Vec<(f64, f64)> = candles
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (i as f64, c.close * fx_rate))
+        .collect();
+    let len = close.len().max(1) as f64;
+
+    let overlay = |value: Option<f64>| -> Vec<(f64, f64)> {
+        match value {
+            Some(v) => vec![(0.0, v * fx_rate), (len - 1.0, v * fx_rate)],
+            None => Vec::new(),
+        }
+    };
+
+    ChartSeries {
+        close,
+        sma50: overlay(indicators.and_then(|i| i.sma50)),
+        sma200: overlay(indicators.and_then(|i| i.sma200)),
+        bb_upper: overlay(indicators.and_then(|i| i.bb_upper)),
+        bb_lower: overlay(indicators.and_then(|i| i.bb_lower)),
+    }
+}
+
+/// Plots the close series for the focused symbol, overlaying the SMA50/200
+/// lines and Bollinger bands already tracked in `TechnicalIndicators`.
+fn build_price_chart<'a>(symbol: &'a str, series: &'a ChartSeries) -> Chart<'a> {
+    let mut datasets = vec![Dataset::default()
+        .name(symbol)
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::Cyan))
+        .data(&series.close)];
+
+    if !series.sma50.is_empty() {
+        datasets.push(
+            Dataset::default()
+                .name("SMA50")
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Green))
+                .data(&series.sma50),
+        );
+    }
+    if !series.sma200.is_empty() {
+        datasets.push(
+            Dataset::default()
+                .name("SMA200")
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Magenta))
+                .data(&series.sma200),
+        );
+    }
+    if !series.bb_upper.is_empty() {
+        datasets.push(
+            Dataset::default()
+                .name("BB upper")
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::DarkGray))
+                .data(&series.bb_upper),
+        );
+    }
+    if !series.bb_lower.is_empty() {
+        datasets.push(
+            Dataset::default()
+                .name("BB lower")
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::DarkGray))
+                .data(&series.bb_lower),
+        );
+    }
+
+    let len = series.close.len().max(1) as f64;
+    let (y_min, y_max) = series
+        .close
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(min, max), (_, y)| (min.min(*y), max.max(*y)));
+    let (y_min, y_max) = if y_min.is_finite() && y_max.is_finite() {
+        (y_min, y_max)
+    } else {
+        (0.0, 1.0)
+    };
+
+    Chart::new(datasets)
+        .block(Block::default().borders(Borders::ALL).title(format!("Chart: {}", symbol)))
+        .x_axis(Axis::default().bounds([0.0, len - 1.0]))
+        .y_axis(
+            Axis::default()
+                .bounds([y_min, y_max])
+                .labels(vec![Span::raw(format!("{:.2}", y_min)), Span::raw(format!("{:.2}", y_max))]),
+        )
+}
+
+/// Runs the crossover rules for every symbol against its previous indicator
+/// snapshot and closing price, appends whatever fires to the rolling signal
+/// history, and rolls `prev_technical_data`/`prev_close_data` forward for
+/// the next refresh. Only meant to be called when `stock_data` just picked
+/// up a new closed candle (a REST refresh or a freshly backfilled symbol),
+/// not on every live-price tick — otherwise a level rule like the Bollinger
+/// band check would re-fire on every tick it stays outside the band.
+fn evaluate_signals(
+    stock_data: &[(String, Value)],
+    technical_data: &[(String, TechnicalIndicators)],
+    prev_technical_data: &mut HashMap<String, TechnicalIndicators>,
+    prev_close_data: &mut HashMap<String, f64>,
+    signal_history: &mut Vec<Signal>,
+) {
+    let ts = chrono::Utc::now().timestamp();
+    for (symbol, curr) in technical_data {
+        let latest_close = stock_data
+            .iter()
+            .find(|(s, _)| s == symbol)
+            .and_then(|(_, data)| data["values"].as_array())
+            .and_then(|values| values.first())
+            .map(|candle| candle["close"].as_str().unwrap_or("0").parse().unwrap_or(0.0))
+            .unwrap_or(0.0);
+
+        let prev = prev_technical_data.get(symbol);
+        let prev_close = prev_close_data.get(symbol).copied();
+        let fresh = signals::evaluate(symbol, prev, curr, prev_close, latest_close, ts);
+        signals::push_history(signal_history, fresh);
+        prev_technical_data.insert(symbol.clone(), *curr);
+        prev_close_data.insert(symbol.clone(), latest_close);
+    }
+}
+
+/// Renders a signal's epoch-seconds `ts` as a readable UTC timestamp for the
+/// Signals panel; falls back to the raw integer on the (practically
+/// unreachable) chance it doesn't fit a valid `NaiveDateTime`.
+fn format_signal_timestamp(ts: i64) -> String {
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| ts.to_string())
+}
+
+fn format_signal_data(signal_history: &[Signal]) -> Vec<Spans<'static>> {
+    signal_history
+        .iter()
+        .rev()
+        .map(|signal| {
+            let color = match signal.kind {
+                signals::SignalKind::Buy => Color::Green,
+                signals::SignalKind::Sell => Color::Red,
+            };
+            Spans::from(Span::styled(
+                format!(
+                    "[{}] {} {}: {}",
+                    format_signal_timestamp(signal.ts),
+                    signal.kind.label(),
+                    signal.symbol,
+                    signal.reason
+                ),
+                Style::default().fg(color),
+            ))
+        })
+        .collect()
+}
+
 async fn fetch_all_stock_data(client: &Client, symbols: &[String]) -> Vec<(String, Value)> {
     let futures = symbols.iter().map(|symbol| {
         let symbol = symbol.clone();
@@ -147,8 +610,8 @@ async fn fetch_all_stock_data(client: &Client, symbols: &[String]) -> Vec<(Strin
 
 async fn fetch_stock_data(client: &Client, symbol: &str) -> Result<Value> {
     let url = format!(
-        "{}?symbol={}&interval=1h&apikey={}",
-        STOCK_API_URL, symbol, STOCK_API_KEY
+        "{}?symbol={}&interval=1h&outputsize={}&apikey={}",
+        STOCK_API_URL, symbol, REST_FETCH_OUTPUT_SIZE, STOCK_API_KEY
     );
     let response = client.get(&url).send().await?;
     let json: Value = response.json().await?;
@@ -189,16 +652,34 @@ async fn fetch_stock_news(client: &Client, symbol: &str) -> Result<Value> {
     Ok(json)
 }
 
-fn format_stock_data(stock_data: &[(String, Value)]) -> Vec<Spans> {
+/// Highlighted when focused, plain borders otherwise, so the user can see
+/// which panel arrow keys / PageUp / PageDown currently act on.
+fn panel_block(title: &str, focused: bool) -> Block {
+    let border_style = if focused {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default()
+    };
+    Block::default().borders(Borders::ALL).border_style(border_style).title(title)
+}
+
+fn format_stock_data<'a>(stock_data: &'a [(String, Value)], app: &App, fx_rate: f64) -> Vec<Spans<'a>> {
     let mut lines = Vec::new();
     for (symbol, data) in stock_data {
         if let Some(values) = data["values"].as_array() {
             if !values.is_empty() {
                 let latest = &values[0];
                 let latest_price: f64 = latest["close"].as_str().unwrap_or("0").parse().unwrap_or(0.0);
+                let is_selected = app.selected_symbol() == Some(symbol.as_str());
+                let prefix = if is_selected { "> " } else { "  " };
+                let name_style = if is_selected {
+                    Style::default().fg(Color::Green).add_modifier(Modifier::REVERSED)
+                } else {
+                    Style::default().fg(Color::Green)
+                };
                 let mut spans = vec![Span::styled(
-                    format!("{}: {:.2}", symbol, latest_price),
-                    Style::default().fg(Color::Green),
+                    format!("{}{}: {:.2}", prefix, symbol, latest_price * fx_rate),
+                    name_style,
                 )];
 
                 if values.len() > 1 {
@@ -245,104 +726,81 @@ fn format_news_data(news_data: &[(String, Value)]) -> Vec<Spans> {
     lines
 }
 
-async fn fetch_all_technical_data(client: &Client, symbols: &[String]) -> Vec<(String, TechnicalIndicators)> {
-    let futures = symbols.iter().map(|symbol| {
-        let symbol = symbol.clone();
-        async move {
-            match fetch_technical_indicators(client, &symbol).await {
-                Ok(indicators) => Some((symbol, indicators)),
-                Err(e) => {
-                    eprintln!("Error fetching technical data for {}: {}", symbol, e);
-                    None
+/// Derives technical indicators for every tracked symbol from its deep
+/// persisted history in `indicator_history`, patching only the most recent
+/// bar with whatever live price `sync_live_prices` already wrote into
+/// `stock_data`. Sourcing from the full lookback instead of the shallow
+/// REST snapshot means SMA200 / the MACD signal line (34+ closes) keep
+/// computing past the first refresh, rather than reverting to `None` once
+/// the REST payload ages out. A symbol with no persisted history falls back
+/// to the candles in its `stock_data` snapshot.
+fn compute_all_technical_data(
+    stock_data: &[(String, Value)],
+    indicator_history: &HashMap<String, Vec<Candle>>,
+) -> Vec<(String, TechnicalIndicators)> {
+    stock_data
+        .iter()
+        .filter_map(|(symbol, data)| {
+            let values: Vec<Value> = match indicator_history.get(symbol).filter(|h| !h.is_empty()) {
+                Some(history) => {
+                    let live_close: f64 = data["values"].as_array()?.first()?["close"].as_str()?.parse().ok()?;
+                    let mut candles = history.clone();
+                    candles[0].close = live_close;
+                    candles.iter().map(candle_to_value).collect()
                 }
-            }
-        }
-    });
-    join_all(futures)
-        .await
-        .into_iter()
-        .filter_map(|data| data)
+                None => data["values"].as_array().filter(|v| !v.is_empty())?.clone(),
+            };
+            Some((symbol.clone(), indicators::compute_technical_indicators(&values)))
+        })
         .collect()
 }
 
-async fn fetch_technical_indicators(client: &Client, symbol: &str) -> Result<TechnicalIndicators> {
-    let sma50 = fetch_indicator_value(client, symbol, "sma", "daily", 50).await?;
-    let sma200 = fetch_indicator_value(client, symbol, "sma", "daily", 200).await?;
-    let rsi = fetch_indicator_value(client, symbol, "rsi", "daily", 14).await?;
-    let macd = fetch_indicator_value(client, symbol, "macd", "daily", 12).await?;
-    let (bb_upper, bb_middle, bb_lower) = fetch_bbands(client, symbol, "daily", 20).await?;
-    Ok(TechnicalIndicators {
-        sma50,
-        sma200,
-        rsi,
-        macd,
-        bb_upper,
-        bb_middle,
-        bb_lower,
-    })
-}
-
-async fn fetch_indicator_value(
-    client: &Client,
-    symbol: &str,
-    indicator: &str,
-    interval: &str,
-    time_period: i32,
-) -> Result<Option<f64>> {
-    let url = format!(
-        "https://api.twelvedata.com/technical_indicator?symbol={}&interval={}&indicator={}&time_period={}&apikey={}",
-        symbol, interval, indicator, time_period, STOCK_API_KEY
-    );
-    let response = client.get(&url).send().await?;
-    let json: Value = response.json().await?;
-    if let Some(values) = json["values"].as_array() {
-        if let Some(latest) = values.first() {
-            if let Some(value_str) = latest[indicator].as_str() {
-                return Ok(Some(value_str.parse().unwrap_or(0.0)));
-            }
-        }
-    }
-    Ok(None)
-}
-
-async fn fetch_bbands(
-    client: &Client,
-    symbol: &str,
-    interval: &str,
-    time_period: i32,
-) -> Result<(Option<f64>, Option<f64>, Option<f64>)> {
-    let url = format!(
-        "https://api.twelvedata.com/technical_indicator?symbol={}&interval={}&indicator=bbands&time_period={}&apikey={}",
-        symbol, interval, time_period, STOCK_API_KEY
-    );
-    let response = client.get(&url).send().await?;
-    let json: Value = response.json().await?;
-    if let Some(values) = json["values"].as_array() {
-        if let Some(latest) = values.first() {
-            let upper = latest["real_upper_band"].as_str().and_then(|s| s.parse().ok());
-            let middle = latest["real_middle_band"].as_str().and_then(|s| s.parse().ok());
-            let lower = latest["real_lower_band"].as_str().and_then(|s| s.parse().ok());
-            return Ok((upper, middle, lower));
-        }
-    }
-    Ok((None, None, None))
-}
-
-fn format_indicator_data(technical_data: &[(String, TechnicalIndicators)]) -> Vec<Spans> {
+fn format_indicator_data(technical_data: &[(String, TechnicalIndicators)], fx_rate: f64) -> Vec<Spans> {
     let mut lines = Vec::new();
     for (symbol, indicators) in technical_data {
+        // RSI is a 0-100 oscillator, not a price, so it's left unconverted.
         let line = format!(
             "{} | SMA50: {:.2?} | SMA200: {:.2?} | RSI: {:.2?} | MACD: {:.2?} | BB: [{:.2?}, {:.2?}, {:.2?}]",
             symbol,
-            indicators.sma50.unwrap_or(0.0),
-            indicators.sma200.unwrap_or(0.0),
+            indicators.sma50.unwrap_or(0.0) * fx_rate,
+            indicators.sma200.unwrap_or(0.0) * fx_rate,
             indicators.rsi.unwrap_or(0.0),
-            indicators.macd.unwrap_or(0.0),
-            indicators.bb_upper.unwrap_or(0.0),
-            indicators.bb_middle.unwrap_or(0.0),
-            indicators.bb_lower.unwrap_or(0.0)
+            indicators.macd.unwrap_or(0.0) * fx_rate,
+            indicators.bb_upper.unwrap_or(0.0) * fx_rate,
+            indicators.bb_middle.unwrap_or(0.0) * fx_rate,
+            indicators.bb_lower.unwrap_or(0.0) * fx_rate
         );
         lines.push(Spans::from(Span::styled(line, Style::default().fg(Color::Magenta))));
     }
     lines
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values_from_closes(closes_oldest_first: &[f64]) -> Vec<Value> {
+        closes_oldest_first.iter().rev().map(|c| serde_json::json!({ "close": c.to_string() })).collect()
+    }
+
+    /// With only the ~30-candle shallow REST snapshot the request
+    /// originally fed into indicators, SMA50/SMA200 stayed `None` forever
+    /// and the golden/death cross rule could never fire. This pins that,
+    /// given the full 200+ candle lookback `compute_all_technical_data`
+    /// now sources from storage, the crossover really does fire.
+    #[test]
+    fn golden_cross_fires_once_indicators_have_full_lookback() {
+        let prev_closes = vec![100.0; 200];
+        let mut curr_closes = prev_closes[1..].to_vec();
+        curr_closes.push(200.0);
+
+        let prev_indicators = indicators::compute_technical_indicators(&values_from_closes(&prev_closes));
+        let curr_indicators = indicators::compute_technical_indicators(&values_from_closes(&curr_closes));
+
+        assert!(prev_indicators.sma50.is_some() && prev_indicators.sma200.is_some());
+        assert!(curr_indicators.sma50.unwrap() > curr_indicators.sma200.unwrap());
+
+        let fired = signals::evaluate("AAPL", Some(&prev_indicators), &curr_indicators, None, 200.0, 0);
+        assert!(fired.iter().any(|s| s.kind == signals::SignalKind::Buy));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::TechnicalIndicators;
+
+/// How many fired signals to keep around for the "Signals" panel history.
+pub const SIGNAL_HISTORY_LIMIT: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignalKind {
+    Buy,
+    Sell,
+}
+
+impl SignalKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SignalKind::Buy => "BUY",
+            SignalKind::Sell => "SELL",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Signal {
+    pub symbol: String,
+    pub kind: SignalKind,
+    pub reason: String,
+    pub ts: i64,
+}
+
+/// Evaluates the classic crossover rules against the previous and current
+/// indicator snapshot for one symbol, emitting a `Signal` per rule that
+/// fired this refresh. `prev_close`/`latest_close` are needed separately
+/// because `TechnicalIndicators` doesn't carry price, only derived values —
+/// the Bollinger rule needs both closes to tell a crossing from a level.
+pub fn evaluate(
+    symbol: &str,
+    prev: Option<&TechnicalIndicators>,
+    curr: &TechnicalIndicators,
+    prev_close: Option<f64>,
+    latest_close: f64,
+    ts: i64,
+) -> Vec<Signal> {
+    let mut signals = Vec::new();
+    let emit = |kind: SignalKind, reason: &str| Signal {
+        symbol: symbol.to_string(),
+        kind,
+        reason: reason.to_string(),
+        ts,
+    };
+
+    if let Some(prev) = prev {
+        if let (Some(p50), Some(p200), Some(c50), Some(c200)) = (prev.sma50, prev.sma200, curr.sma50, curr.sma200) {
+            if p50 <= p200 && c50 > c200 {
+                signals.push(emit(SignalKind::Buy, "golden cross: SMA50 crossed above SMA200"));
+            } else if p50 >= p200 && c50 < c200 {
+                signals.push(emit(SignalKind::Sell, "death cross: SMA50 crossed below SMA200"));
+            }
+        }
+
+        if let (Some(p), Some(c)) = (prev.rsi, curr.rsi) {
+            if p < 70.0 && c >= 70.0 {
+                signals.push(emit(SignalKind::Sell, "RSI crossed above 70 (overbought)"));
+            } else if p > 30.0 && c <= 30.0 {
+                signals.push(emit(SignalKind::Buy, "RSI crossed below 30 (oversold)"));
+            }
+        }
+
+        if let (Some(pm), Some(ps), Some(cm), Some(cs)) = (prev.macd, prev.macd_signal, curr.macd, curr.macd_signal) {
+            if pm <= ps && cm > cs {
+                signals.push(emit(SignalKind::Buy, "MACD crossed above its signal line"));
+            } else if pm >= ps && cm < cs {
+                signals.push(emit(SignalKind::Sell, "MACD crossed below its signal line"));
+            }
+        }
+
+        // Bollinger bands move every refresh, so "outside the band" is a
+        // level, not an event — only fire on the refresh where the close
+        // actually crosses from inside to outside, using each side's own
+        // band at the time, or a sustained breakout re-fires every tick.
+        if let (Some(prev_close), Some(prev_upper), Some(upper)) = (prev_close, prev.bb_upper, curr.bb_upper) {
+            if prev_close <= prev_upper && latest_close > upper {
+                signals.push(emit(SignalKind::Sell, "price crossed above the upper Bollinger band"));
+            }
+        }
+        if let (Some(prev_close), Some(prev_lower), Some(lower)) = (prev_close, prev.bb_lower, curr.bb_lower) {
+            if prev_close >= prev_lower && latest_close < lower {
+                signals.push(emit(SignalKind::Buy, "price crossed below the lower Bollinger band"));
+            }
+        }
+    }
+
+    signals
+}
+
+/// Appends freshly-fired signals to the rolling history, dropping the
+/// oldest entries once it exceeds `SIGNAL_HISTORY_LIMIT`.
+pub fn push_history(history: &mut Vec<Signal>, mut fresh: Vec<Signal>) {
+    history.append(&mut fresh);
+    if history.len() > SIGNAL_HISTORY_LIMIT {
+        let excess = history.len() - SIGNAL_HISTORY_LIMIT;
+        history.drain(0..excess);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indicators(sma50: f64, sma200: f64) -> TechnicalIndicators {
+        TechnicalIndicators {
+            sma50: Some(sma50),
+            sma200: Some(sma200),
+            rsi: None,
+            macd: None,
+            macd_signal: None,
+            bb_upper: None,
+            bb_middle: None,
+            bb_lower: None,
+        }
+    }
+
+    #[test]
+    fn golden_cross_fires_buy() {
+        let prev = indicators(99.0, 100.0);
+        let curr = indicators(101.0, 100.0);
+        let fired = evaluate("AAPL", Some(&prev), &curr, None, 101.0, 0);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].kind, SignalKind::Buy);
+    }
+
+    #[test]
+    fn death_cross_fires_sell() {
+        let prev = indicators(101.0, 100.0);
+        let curr = indicators(99.0, 100.0);
+        let fired = evaluate("AAPL", Some(&prev), &curr, None, 99.0, 0);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].kind, SignalKind::Sell);
+    }
+
+    #[test]
+    fn no_prior_snapshot_means_no_signals() {
+        let curr = indicators(101.0, 100.0);
+        assert!(evaluate("AAPL", None, &curr, None, 101.0, 0).is_empty());
+    }
+
+    fn with_bb(upper: f64, lower: f64) -> TechnicalIndicators {
+        TechnicalIndicators {
+            sma50: None,
+            sma200: None,
+            rsi: None,
+            macd: None,
+            macd_signal: None,
+            bb_upper: Some(upper),
+            bb_middle: None,
+            bb_lower: Some(lower),
+        }
+    }
+
+    #[test]
+    fn band_breakout_fires_once_on_the_crossing_refresh() {
+        let prev = with_bb(110.0, 90.0);
+        let curr = with_bb(110.0, 90.0);
+
+        // Refresh where the close actually crosses above the upper band.
+        let crossing = evaluate("AAPL", Some(&prev), &curr, Some(105.0), 111.0, 0);
+        assert_eq!(crossing.len(), 1);
+        assert_eq!(crossing[0].kind, SignalKind::Sell);
+
+        // Next refresh: price is still outside the band but didn't just
+        // cross it (prev_close was already outside too) — must not re-fire.
+        let still_outside = evaluate("AAPL", Some(&prev), &curr, Some(111.0), 112.0, 0);
+        assert!(still_outside.is_empty());
+    }
+}
@@ -0,0 +1,173 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use deadpool_sqlite::{Config, Pool, Runtime};
+use reqwest::Client;
+use rusqlite::params;
+use serde_json::Value;
+
+use crate::STOCK_API_URL;
+
+/// A single OHLCV bar, keyed by (symbol, timestamp, resolution) once
+/// persisted — that triple is the idempotent upsert key so a re-fetched
+/// candle just overwrites itself instead of duplicating.
+#[derive(Debug, Clone)]
+pub struct Candle {
+    pub timestamp: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+/// How many historical candles to pull from Twelve Data on a cold-start
+/// backfill; deep enough to seed SMA200 the moment the app comes up.
+const BACKFILL_OUTPUT_SIZE: u32 = 5000;
+
+/// Persists candles keyed by (symbol, timestamp, resolution) and backfills
+/// gaps from Twelve Data, so price history and indicator lookback survive
+/// a restart instead of starting cold every run.
+#[async_trait]
+pub trait Storage: Send + Sync {
+    async fn persist_candles(&self, symbol: &str, resolution: &str, candles: &[Candle]) -> Result<()>;
+    async fn load_recent(&self, symbol: &str, resolution: &str, limit: usize) -> Result<Vec<Candle>>;
+    async fn backfill(&self, client: &Client, symbol: &str, resolution: &str, api_key: &str) -> Result<()>;
+}
+
+pub struct SqliteStorage {
+    pool: Pool,
+}
+
+impl SqliteStorage {
+    pub async fn connect(path: &str) -> Result<Self> {
+        let pool = Config::new(path).create_pool(Runtime::Tokio1)?;
+        let conn = pool.get().await?;
+        conn.interact(|conn| {
+            conn.execute_batch(
+                "CREATE TABLE IF NOT EXISTS candles (
+                    symbol TEXT NOT NULL,
+                    resolution TEXT NOT NULL,
+                    ts INTEGER NOT NULL,
+                    open REAL NOT NULL,
+                    high REAL NOT NULL,
+                    low REAL NOT NULL,
+                    close REAL NOT NULL,
+                    volume REAL NOT NULL,
+                    PRIMARY KEY (symbol, resolution, ts)
+                )",
+            )
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("sqlite init task failed: {:?}", e))??;
+        Ok(Self { pool })
+    }
+}
+
+#[async_trait]
+impl Storage for SqliteStorage {
+    async fn persist_candles(&self, symbol: &str, resolution: &str, candles: &[Candle]) -> Result<()> {
+        let conn = self.pool.get().await?;
+        let symbol = symbol.to_string();
+        let resolution = resolution.to_string();
+        let candles = candles.to_vec();
+        conn.interact(move |conn| -> rusqlite::Result<()> {
+            let tx = conn.transaction()?;
+            {
+                let mut stmt = tx.prepare(
+                    "INSERT INTO candles (symbol, resolution, ts, open, high, low, close, volume)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                     ON CONFLICT(symbol, resolution, ts) DO UPDATE SET
+                        open = excluded.open,
+                        high = excluded.high,
+                        low = excluded.low,
+                        close = excluded.close,
+                        volume = excluded.volume",
+                )?;
+                for candle in &candles {
+                    stmt.execute(params![
+                        symbol,
+                        resolution,
+                        candle.timestamp,
+                        candle.open,
+                        candle.high,
+                        candle.low,
+                        candle.close,
+                        candle.volume,
+                    ])?;
+                }
+            }
+            tx.commit()
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("sqlite persist task failed: {:?}", e))??;
+        Ok(())
+    }
+
+    async fn load_recent(&self, symbol: &str, resolution: &str, limit: usize) -> Result<Vec<Candle>> {
+        let conn = self.pool.get().await?;
+        let symbol = symbol.to_string();
+        let resolution = resolution.to_string();
+        let candles = conn
+            .interact(move |conn| -> rusqlite::Result<Vec<Candle>> {
+                let mut stmt = conn.prepare(
+                    "SELECT ts, open, high, low, close, volume FROM candles
+                     WHERE symbol = ?1 AND resolution = ?2
+                     ORDER BY ts DESC LIMIT ?3",
+                )?;
+                let rows = stmt.query_map(params![symbol, resolution, limit as i64], |row| {
+                    Ok(Candle {
+                        timestamp: row.get(0)?,
+                        open: row.get(1)?,
+                        high: row.get(2)?,
+                        low: row.get(3)?,
+                        close: row.get(4)?,
+                        volume: row.get(5)?,
+                    })
+                })?;
+                rows.collect()
+            })
+            .await
+            .map_err(|e| anyhow::anyhow!("sqlite load task failed: {:?}", e))??;
+        Ok(candles)
+    }
+
+    async fn backfill(&self, client: &Client, symbol: &str, resolution: &str, api_key: &str) -> Result<()> {
+        let latest = self.load_recent(symbol, resolution, 1).await?;
+        let since = latest.first().map(|c| c.timestamp);
+
+        let url = format!(
+            "{}?symbol={}&interval=1h&outputsize={}&apikey={}",
+            STOCK_API_URL, symbol, BACKFILL_OUTPUT_SIZE, api_key
+        );
+        let response = client.get(&url).send().await?;
+        let json: Value = response.json().await?;
+        let Some(values) = json["values"].as_array() else {
+            return Ok(());
+        };
+
+        let candles: Vec<Candle> = values
+            .iter()
+            .filter_map(|candle| parse_candle(candle))
+            .filter(|candle| since.map_or(true, |since| candle.timestamp > since))
+            .collect();
+
+        if !candles.is_empty() {
+            self.persist_candles(symbol, resolution, &candles).await?;
+        }
+        Ok(())
+    }
+}
+
+pub fn parse_candle(candle: &Value) -> Option<Candle> {
+    let datetime = candle["datetime"].as_str()?;
+    let timestamp = chrono::NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| chrono::NaiveDate::parse_from_str(datetime, "%Y-%m-%d").map(|d| d.and_hms_opt(0, 0, 0).unwrap()))
+        .ok()?
+        .timestamp();
+    let open: f64 = candle["open"].as_str()?.parse().ok()?;
+    let high: f64 = candle["high"].as_str()?.parse().ok()?;
+    let low: f64 = candle["low"].as_str()?.parse().ok()?;
+    let close: f64 = candle["close"].as_str()?.parse().ok()?;
+    let volume: f64 = candle["volume"].as_str().and_then(|v| v.parse().ok()).unwrap_or(0.0);
+    Some(Candle { timestamp, open, high, low, close, volume })
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+use tokio_tungstenite::tungstenite::Message;
+
+const TWELVE_DATA_WS_URL: &str = "wss://ws.twelvedata.com/v1/quotes/price";
+const RECONNECT_DELAY: Duration = Duration::from_secs(3);
+
+/// Latest live tick for a single symbol, as seen over the socket. Kept
+/// separate from the persisted candle history so a tick updates only the
+/// in-progress bar, never overwrites an already-closed one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PriceSnapshot {
+    pub latest: f64,
+}
+
+/// Pushed off the wire purely to wake the render loop — the main loop
+/// selects on the channel but reads the actual price back out of
+/// `PriceMap`, so there's nothing to carry here.
+#[derive(Debug, Clone, Copy)]
+pub struct PriceUpdate;
+
+pub type PriceMap = Arc<Mutex<HashMap<String, PriceSnapshot>>>;
+
+#[derive(Debug, Deserialize)]
+struct RawPriceEvent {
+    event: Option<String>,
+    symbol: Option<String>,
+    price: Option<f64>,
+}
+
+/// Spawns a background task that keeps a Twelve Data price socket open,
+/// reconnecting whenever the connection drops, and folds every tick into
+/// `prices`. Returns the shared map, a channel the render loop selects on
+/// to know a redraw is due, and the task's `JoinHandle` — the subscription
+/// is sent once at connect time, so picking up a watchlist change means
+/// aborting this handle and calling `spawn_price_feed` again rather than
+/// mutating the running task.
+pub fn spawn_price_feed(api_key: String, symbols: Vec<String>) -> (PriceMap, mpsc::Receiver<PriceUpdate>, JoinHandle<()>) {
+    let prices: PriceMap = Arc::new(Mutex::new(HashMap::new()));
+    let (tx, rx) = mpsc::channel(256);
+
+    let feed_prices = prices.clone();
+    let handle = tokio::spawn(async move {
+        loop {
+            if let Err(e) = run_feed(&api_key, &symbols, &feed_prices, &tx).await {
+                crate::logging::log_error(&format!("price feed disconnected: {}", e));
+            }
+            sleep(RECONNECT_DELAY).await;
+        }
+    });
+
+    (prices, rx, handle)
+}
+
+async fn run_feed(
+    api_key: &str,
+    symbols: &[String],
+    prices: &PriceMap,
+    tx: &mpsc::Sender<PriceUpdate>,
+) -> anyhow::Result<()> {
+    let url = format!("{}?apikey={}", TWELVE_DATA_WS_URL, api_key);
+    let (mut ws_stream, _) = tokio_tungstenite::connect_async(url).await?;
+
+    let subscribe = serde_json::json!({
+        "action": "subscribe",
+        "params": { "symbols": symbols.join(",") },
+    });
+    ws_stream.send(Message::Text(subscribe.to_string())).await?;
+
+    while let Some(msg) = ws_stream.next().await {
+        let msg = msg?;
+        let text = match msg {
+            Message::Text(text) => text,
+            Message::Ping(payload) => {
+                ws_stream.send(Message::Pong(payload)).await?;
+                continue;
+            }
+            Message::Close(_) => break,
+            _ => continue,
+        };
+
+        let Ok(event) = serde_json::from_str::<RawPriceEvent>(&text) else {
+            continue;
+        };
+        if event.event.as_deref() != Some("price") {
+            continue;
+        }
+        let (Some(symbol), Some(price)) = (event.symbol, event.price) else {
+            continue;
+        };
+
+        {
+            let mut map = prices.lock().await;
+            map.entry(symbol).or_default().latest = price;
+        }
+
+        // The receiver only cares that a tick arrived; a full channel just
+        // means a redraw is already pending, so drop rather than block.
+        let _ = tx.try_send(PriceUpdate);
+    }
+
+    Ok(())
+}